@@ -1,78 +1,220 @@
 use std::{
-    collections::HashSet,
-    sync::{atomic::AtomicBool, Arc, OnceLock},
+    collections::{HashMap, HashSet},
+    num::NonZeroUsize,
+    path::PathBuf,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+    task::{Context, Poll},
     time::Duration,
 };
 
+use futures::Stream;
 use reqwest::header::{HeaderValue, CONTENT_TYPE};
+use sha2::{Digest, Sha256};
+use tracing::Instrument;
 
 use crate::{DeadlineMonitor, Result};
 
+const FETCH_DEADLINE: Duration = Duration::from_millis(4_000);
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct Voice {
     pub id: String,
     pub name: String,
 }
 
+/// A single Gwent daemon replica, tracked for health independently of the
+/// others so one bad replica can't stall the whole service.
+struct DaemonEndpoint {
+    base_url: reqwest::Url,
+    healthy: AtomicBool,
+}
+
+impl DaemonEndpoint {
+    fn url(&self, path: &str) -> reqwest::Url {
+        let mut url = self.base_url.clone();
+        url.set_path(path);
+        url
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.healthy.load(Ordering::Relaxed)
+    }
+
+    fn mark(&self, healthy: bool) {
+        self.healthy.store(healthy, Ordering::Relaxed);
+    }
+}
+
 pub struct State {
-    daemon_url: reqwest::Url,
+    endpoints: Vec<Arc<DaemonEndpoint>>,
+    next_endpoint: AtomicUsize,
     health_path: String,
     voices_path: String,
     tts_path: String,
     semaphore: Arc<tokio::sync::Semaphore>,
     client: reqwest::Client,
+    cache: TtsCache,
+    chunk_threshold: usize,
+    metrics: TtsMetrics,
 }
 
 impl State {
     pub async fn new() -> Result<Self> {
-        let daemon_url = std::env::var("GWENT_DAEMON_URL")
-            .unwrap_or_else(|_| String::from("http://127.0.0.1:9000"))
-            .parse()?;
+        let daemon_urls = std::env::var("GWENT_DAEMON_URL")
+            .unwrap_or_else(|_| String::from("http://127.0.0.1:9000"));
+
+        let endpoints = daemon_urls
+            .split(',')
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .map(|url| {
+                Ok(Arc::new(DaemonEndpoint {
+                    base_url: url.parse()?,
+                    healthy: AtomicBool::new(true),
+                }))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if endpoints.is_empty() {
+            anyhow::bail!("GWENT_DAEMON_URL must contain at least one endpoint");
+        }
 
         let connect_timeout = parse_env_u64("GWENT_CONNECT_TIMEOUT_MS", 500)?;
         let request_timeout = parse_env_u64("GWENT_REQUEST_TIMEOUT_MS", 10_000)?;
         let max_concurrency = parse_env_u64("GWENT_MAX_CONCURRENCY", 32)?;
         let max_concurrency = usize::try_from(max_concurrency)?;
+        let health_poll_interval = parse_env_u64("GWENT_HEALTH_POLL_INTERVAL_MS", 15_000)?;
+        let chunk_threshold = usize::try_from(parse_env_u64("GWENT_CHUNK_THRESHOLD", 500)?)?;
 
         if max_concurrency == 0 {
             anyhow::bail!("GWENT_MAX_CONCURRENCY must be greater than 0");
         }
 
+        let health_path = env_path("GWENT_HEALTH_PATH", "/health");
+        let client = reqwest::Client::builder()
+            .connect_timeout(Duration::from_millis(connect_timeout))
+            .timeout(Duration::from_millis(request_timeout))
+            .build()?;
+
         let state = Self {
-            daemon_url,
-            health_path: env_path("GWENT_HEALTH_PATH", "/health"),
+            endpoints,
+            next_endpoint: AtomicUsize::new(0),
+            health_path,
             voices_path: env_path("GWENT_VOICES_PATH", "/voices"),
             tts_path: env_path("GWENT_TTS_PATH", "/tts"),
             semaphore: Arc::new(tokio::sync::Semaphore::new(max_concurrency)),
-            client: reqwest::Client::builder()
-                .connect_timeout(Duration::from_millis(connect_timeout))
-                .timeout(Duration::from_millis(request_timeout))
-                .build()?,
+            client,
+            cache: TtsCache::from_env()?,
+            chunk_threshold,
+            metrics: TtsMetrics::default(),
         };
 
         state.probe_daemon().await;
+        state.spawn_health_monitor(Duration::from_millis(health_poll_interval));
         Ok(state)
     }
 
-    fn endpoint_url(&self, path: &str) -> reqwest::Url {
-        let mut url = self.daemon_url.clone();
-        url.set_path(path);
-        url
+    /// Aggregated up/down status of every configured daemon endpoint,
+    /// keyed by base URL, suitable for a readiness report.
+    pub fn daemon_status(&self) -> Vec<(String, bool)> {
+        self.endpoints
+            .iter()
+            .map(|endpoint| (endpoint.base_url.to_string(), endpoint.is_healthy()))
+            .collect()
     }
 
-    async fn probe_daemon(&self) {
-        let health_url = self.endpoint_url(&self.health_path);
-        match self.client.get(health_url).send().await {
-            Ok(resp) if resp.status().is_success() => {
-                tracing::info!("Gwent daemon healthcheck passed");
-            }
-            Ok(resp) => {
-                tracing::warn!("Gwent daemon healthcheck returned {}", resp.status());
-                return;
+    /// Point-in-time snapshot of synthesis counters, for scraping into a
+    /// dashboard.
+    pub fn metrics_snapshot(&self) -> TtsMetricsSnapshot {
+        self.metrics.snapshot()
+    }
+
+    fn spawn_health_monitor(&self, interval: Duration) {
+        let client = self.client.clone();
+        let health_path = self.health_path.clone();
+        let endpoints = self.endpoints.clone();
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                for endpoint in &endpoints {
+                    let was_healthy = endpoint.is_healthy();
+                    let health_url = endpoint.url(&health_path);
+                    let is_healthy = matches!(
+                        client.get(health_url).send().await,
+                        Ok(resp) if resp.status().is_success()
+                    );
+                    endpoint.mark(is_healthy);
+
+                    if is_healthy && !was_healthy {
+                        tracing::info!("Gwent daemon {} recovered", endpoint.base_url);
+                    } else if !is_healthy && was_healthy {
+                        tracing::warn!("Gwent daemon {} failed healthcheck", endpoint.base_url);
+                    }
+                }
             }
-            Err(err) => {
-                tracing::warn!("Unable to reach Gwent daemon at startup: {err}");
-                return;
+        });
+    }
+
+    /// Picks the next healthy endpoint in round-robin order, skipping ones
+    /// whose circuit breaker has tripped. Equivalent to
+    /// `pick_endpoint_excluding` with nothing excluded.
+    fn pick_endpoint(&self) -> Arc<DaemonEndpoint> {
+        self.pick_endpoint_excluding(&HashSet::new()).1
+    }
+
+    /// Picks the next round-robin endpoint, skipping both unhealthy
+    /// endpoints and the indices in `exclude` (the ones this call's retry
+    /// loop has already attempted). Falls back to an untried endpoint
+    /// ignoring health if every remaining one is down, and only as a true
+    /// last resort (every endpoint already tried) repeats one, so retries
+    /// spread across replicas instead of hammering the same one.
+    fn pick_endpoint_excluding(&self, exclude: &HashSet<usize>) -> (usize, Arc<DaemonEndpoint>) {
+        let start = self.next_endpoint.fetch_add(1, Ordering::Relaxed);
+        let len = self.endpoints.len();
+
+        let healthy_untried = (0..len)
+            .map(|offset| (start + offset) % len)
+            .find(|idx| !exclude.contains(idx) && self.endpoints[*idx].is_healthy());
+        if let Some(idx) = healthy_untried {
+            return (idx, self.endpoints[idx].clone());
+        }
+
+        let untried = (0..len)
+            .map(|offset| (start + offset) % len)
+            .find(|idx| !exclude.contains(idx));
+        if let Some(idx) = untried {
+            return (idx, self.endpoints[idx].clone());
+        }
+
+        let idx = start % len;
+        (idx, self.endpoints[idx].clone())
+    }
+
+    async fn probe_daemon(&self) {
+        for endpoint in &self.endpoints {
+            let health_url = endpoint.url(&self.health_path);
+            match self.client.get(health_url).send().await {
+                Ok(resp) if resp.status().is_success() => {
+                    tracing::info!("Gwent daemon {} healthcheck passed", endpoint.base_url);
+                }
+                Ok(resp) => {
+                    tracing::warn!(
+                        "Gwent daemon {} healthcheck returned {}",
+                        endpoint.base_url,
+                        resp.status()
+                    );
+                    endpoint.mark(false);
+                }
+                Err(err) => {
+                    tracing::warn!("Unable to reach Gwent daemon {}: {err}", endpoint.base_url);
+                    endpoint.mark(false);
+                }
             }
         }
 
@@ -101,7 +243,7 @@ impl State {
     }
 
     async fn fetch_daemon_voice_ids(&self) -> Result<HashSet<String>> {
-        let voices_url = self.endpoint_url(&self.voices_path);
+        let voices_url = self.pick_endpoint().url(&self.voices_path);
         let resp = self
             .client
             .get(voices_url)
@@ -127,6 +269,186 @@ impl State {
     }
 }
 
+struct CacheEntry {
+    audio: bytes::Bytes,
+    content_type: Option<HeaderValue>,
+}
+
+/// LRU cache of synthesized clips keyed on a content hash of the request
+/// parameters, with an optional on-disk spill directory so hot clips (e.g.
+/// a soundboard's canned responses) survive process restarts.
+struct TtsCache {
+    entries: tokio::sync::Mutex<lru::LruCache<String, CacheEntry>>,
+    disk_dir: Option<PathBuf>,
+}
+
+impl TtsCache {
+    fn from_env() -> Result<Self> {
+        let max_entries = parse_env_u64("GWENT_CACHE_MAX_ENTRIES", 256)?;
+        let max_entries = usize::try_from(max_entries)?;
+        let capacity = NonZeroUsize::new(max_entries)
+            .ok_or_else(|| anyhow::anyhow!("GWENT_CACHE_MAX_ENTRIES must be greater than 0"))?;
+
+        let disk_dir = std::env::var("GWENT_CACHE_DIR").ok().map(PathBuf::from);
+        if let Some(dir) = &disk_dir {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        Ok(Self {
+            entries: tokio::sync::Mutex::new(lru::LruCache::new(capacity)),
+            disk_dir,
+        })
+    }
+
+    fn disk_paths(&self, key: &str) -> Option<(PathBuf, PathBuf)> {
+        let dir = self.disk_dir.as_ref()?;
+        Some((
+            dir.join(format!("{key}.bin")),
+            dir.join(format!("{key}.ct")),
+        ))
+    }
+
+    async fn get(&self, key: &str) -> Option<(bytes::Bytes, Option<HeaderValue>)> {
+        if let Some(entry) = self.entries.lock().await.get(key) {
+            return Some((entry.audio.clone(), entry.content_type.clone()));
+        }
+
+        let (audio_path, ct_path) = self.disk_paths(key)?;
+        let audio = tokio::fs::read(&audio_path).await.ok()?;
+        let content_type = tokio::fs::read_to_string(&ct_path)
+            .await
+            .ok()
+            .and_then(|s| HeaderValue::from_str(&s).ok());
+
+        let audio = bytes::Bytes::from(audio);
+        self.insert_in_memory(
+            key.to_owned(),
+            CacheEntry {
+                audio: audio.clone(),
+                content_type: content_type.clone(),
+            },
+        )
+        .await;
+
+        Some((audio, content_type))
+    }
+
+    async fn put(&self, key: String, audio: bytes::Bytes, content_type: Option<HeaderValue>) {
+        if let Some((audio_path, ct_path)) = self.disk_paths(&key) {
+            if let Err(err) = tokio::fs::write(&audio_path, &audio).await {
+                tracing::warn!("Failed to spill Gwent cache entry to disk: {err}");
+            } else if let Some(ct) = &content_type {
+                if let Ok(ct_str) = ct.to_str() {
+                    if let Err(err) = tokio::fs::write(&ct_path, ct_str).await {
+                        tracing::warn!("Failed to write Gwent cache content-type sidecar: {err}");
+                    }
+                }
+            }
+        }
+
+        self.insert_in_memory(
+            key,
+            CacheEntry {
+                audio,
+                content_type,
+            },
+        )
+        .await;
+    }
+
+    /// Inserts into the in-memory LRU, deleting the disk-spilled pair for
+    /// whichever entry the LRU evicts to make room, so files written or
+    /// loaded by this process stay bounded by `GWENT_CACHE_MAX_ENTRIES`.
+    /// Files left in `GWENT_CACHE_DIR` by earlier runs are only removed
+    /// once they are loaded back into memory and later evicted.
+    async fn insert_in_memory(&self, key: String, entry: CacheEntry) {
+        let evicted = self.entries.lock().await.push(key.clone(), entry);
+        let Some((evicted_key, _)) = evicted else {
+            return;
+        };
+        if evicted_key == key {
+            return;
+        }
+        if let Some((audio_path, ct_path)) = self.disk_paths(&evicted_key) {
+            let _ = tokio::fs::remove_file(&audio_path).await;
+            let _ = tokio::fs::remove_file(&ct_path).await;
+        }
+    }
+}
+
+/// Point-in-time counters returned by [`State::metrics_snapshot`].
+pub struct TtsMetricsSnapshot {
+    pub successes: u64,
+    pub deadline_breaches: u64,
+    /// Failure counts keyed by HTTP status code; a key of `0` means the
+    /// daemon request never got a response at all (connect/timeout error).
+    pub failures_by_status: HashMap<u16, u64>,
+}
+
+/// Counters of Gwent daemon synthesis requests (one success per chunk
+/// fetched by `get_tts` or stream opened by `get_tts_stream`, one failure
+/// per failed endpoint attempt; cache hits are not counted), scraped via
+/// [`State::metrics_snapshot`] for latency/error-rate dashboards.
+#[derive(Default)]
+struct TtsMetrics {
+    successes: AtomicU64,
+    deadline_breaches: AtomicU64,
+    failures_by_status: Mutex<HashMap<u16, u64>>,
+}
+
+impl TtsMetrics {
+    fn record_success(&self) {
+        self.successes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_deadline_breach(&self) {
+        self.deadline_breaches.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_failure(&self, status: Option<u16>) {
+        let mut failures = self.failures_by_status.lock().unwrap();
+        *failures.entry(status.unwrap_or(0)).or_insert(0) += 1;
+    }
+
+    fn snapshot(&self) -> TtsMetricsSnapshot {
+        TtsMetricsSnapshot {
+            successes: self.successes.load(Ordering::Relaxed),
+            deadline_breaches: self.deadline_breaches.load(Ordering::Relaxed),
+            failures_by_status: self.failures_by_status.lock().unwrap().clone(),
+        }
+    }
+}
+
+fn cache_key(
+    text: &str,
+    voice: &str,
+    speaking_rate: f32,
+    format: AudioFormat,
+    max_length: Option<u64>,
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update((text.len() as u64).to_le_bytes());
+    hasher.update(text.as_bytes());
+    hasher.update((voice.len() as u64).to_le_bytes());
+    hasher.update(voice.as_bytes());
+    hasher.update(speaking_rate.to_bits().to_le_bytes());
+    let format_bytes = format.as_str().as_bytes();
+    hasher.update((format_bytes.len() as u64).to_le_bytes());
+    hasher.update(format_bytes);
+    // Tag `max_length` explicitly rather than collapsing `None` to `0`:
+    // `None` skips the daemon's cap entirely, while `Some(0)` forwards a
+    // real (if degenerate) cap, so the two must never share a cache key.
+    match max_length {
+        Some(len) => {
+            hasher.update([1u8]);
+            hasher.update(len.to_le_bytes());
+        }
+        None => hasher.update([0u8]),
+    }
+
+    format!("{:x}", hasher.finalize())
+}
+
 fn parse_env_u64(name: &str, default: u64) -> Result<u64> {
     match std::env::var(name) {
         Ok(value) => Ok(value.parse::<u64>()?),
@@ -170,12 +492,21 @@ pub fn check_voice(voice: &str) -> bool {
 enum AudioFormat {
     Ogg,
     Mp3,
+    /// Raw Opus frames at 48 kHz stereo, ready for Discord voice (RTP) without
+    /// a decode/re-encode pass.
+    Opus,
+    /// The DCA container songbird's `Input` reads natively.
+    Dca,
 }
 
 impl AudioFormat {
     fn parse(preferred: Option<&str>) -> Self {
         if preferred.is_some_and(|f| f.eq_ignore_ascii_case("mp3")) {
             Self::Mp3
+        } else if preferred.is_some_and(|f| f.eq_ignore_ascii_case("opus")) {
+            Self::Opus
+        } else if preferred.is_some_and(|f| f.eq_ignore_ascii_case("dca")) {
+            Self::Dca
         } else {
             Self::Ogg
         }
@@ -185,6 +516,8 @@ impl AudioFormat {
         match self {
             Self::Mp3 => "mp3",
             Self::Ogg => "ogg",
+            Self::Opus => "opus",
+            Self::Dca => "dca",
         }
     }
 
@@ -192,8 +525,25 @@ impl AudioFormat {
         match self {
             Self::Mp3 => HeaderValue::from_static("audio/mpeg"),
             Self::Ogg => HeaderValue::from_static("audio/ogg"),
+            Self::Opus => HeaderValue::from_static("audio/opus"),
+            Self::Dca => HeaderValue::from_static("application/octet-stream"),
         }
     }
+
+    /// Whether naively concatenating per-chunk byte buffers of this format
+    /// produces a single gapless clip. `Ogg` pages carry sequence numbers
+    /// and a stream serial, so stitched chunks need real container surgery;
+    /// the others are flat frame/sample streams that play back-to-back fine.
+    fn supports_gapless_concatenation(self) -> bool {
+        !matches!(self, Self::Ogg)
+    }
+}
+
+/// Whether chunked output for `format` (as accepted by `get_tts`'s
+/// `preferred_format`) can be concatenated into a single gapless clip, or
+/// only played back as a back-to-back sequence of separate clips.
+pub fn format_supports_gapless_concatenation(preferred_format: Option<&str>) -> bool {
+    AudioFormat::parse(preferred_format).supports_gapless_concatenation()
 }
 
 #[derive(serde::Serialize)]
@@ -206,21 +556,162 @@ struct TtsRequest<'a> {
     max_length: Option<u64>,
 }
 
-pub async fn get_tts(
+/// Sends the TTS request to a healthy endpoint, failing over to the next
+/// one (and tripping the losing endpoint's circuit breaker) if it errors or
+/// returns a non-success status.
+async fn request_tts(state: &State, payload: &TtsRequest<'_>) -> Result<reqwest::Response> {
+    let mut last_err = None;
+    let mut tried = HashSet::new();
+
+    for _ in 0..state.endpoints.len() {
+        let (idx, endpoint) = state.pick_endpoint_excluding(&tried);
+        tried.insert(idx);
+        let req_url = endpoint.url(&state.tts_path);
+
+        match state.client.post(req_url).json(payload).send().await {
+            Ok(resp) if resp.status().is_success() => return Ok(resp),
+            Ok(resp) => {
+                let status = resp.status();
+                let body = resp.text().await.unwrap_or_default();
+                tracing::warn!(
+                    "Gwent daemon {} request failed ({status}): {body}",
+                    endpoint.base_url
+                );
+                endpoint.mark(false);
+                state.metrics.record_failure(Some(status.as_u16()));
+                last_err = Some(anyhow::anyhow!(
+                    "Gwent daemon request failed ({status}): {body}"
+                ));
+            }
+            Err(err) => {
+                tracing::warn!("Gwent daemon {} request errored: {err}", endpoint.base_url);
+                endpoint.mark(false);
+                state.metrics.record_failure(None);
+                last_err = Some(err.into());
+            }
+        }
+    }
+
+    Err(last_err.unwrap_or_else(|| anyhow::anyhow!("No Gwent daemon endpoints configured")))
+}
+
+/// Splits `text` into pieces no longer than `max_len` chars, preferring to
+/// break on a sentence/clause boundary (`.`, `!`, `?`, `;`, `,`) so each
+/// chunk reads naturally on its own. Falls back to a hard cut if a single
+/// clause overruns `max_len`.
+fn split_into_chunks(text: &str, max_len: usize) -> Vec<String> {
+    if max_len == 0 || text.chars().count() <= max_len {
+        return vec![text.to_owned()];
+    }
+
+    let is_boundary = |c: char| matches!(c, '.' | '!' | '?' | ';' | ',');
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut last_boundary_len = 0;
+
+    for c in text.chars() {
+        current.push(c);
+        if is_boundary(c) {
+            last_boundary_len = current.chars().count();
+        }
+
+        if current.chars().count() >= max_len {
+            let split_at = if last_boundary_len > 0 {
+                last_boundary_len
+            } else {
+                current.chars().count()
+            };
+            let byte_idx = current
+                .char_indices()
+                .nth(split_at)
+                .map_or(current.len(), |(idx, _)| idx);
+
+            let rest = current.split_off(byte_idx);
+            let chunk = current.trim();
+            if !chunk.is_empty() {
+                chunks.push(chunk.to_owned());
+            }
+            current = rest;
+            last_boundary_len = 0;
+        }
+    }
+
+    let remainder = current.trim();
+    if !remainder.is_empty() {
+        chunks.push(remainder.to_owned());
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod split_into_chunks_tests {
+    use super::split_into_chunks;
+
+    #[test]
+    fn empty_text_returns_a_single_empty_chunk() {
+        assert_eq!(split_into_chunks("", 10), vec![""]);
+    }
+
+    #[test]
+    fn text_at_or_under_max_len_is_not_split() {
+        assert_eq!(split_into_chunks("12345", 5), vec!["12345"]);
+        assert_eq!(split_into_chunks("1234", 5), vec!["1234"]);
+    }
+
+    #[test]
+    fn splits_on_sentence_and_clause_boundaries() {
+        let chunks = split_into_chunks("Hello world. This is a test.", 16);
+        assert_eq!(chunks, vec!["Hello world.", "This is a test."]);
+    }
+
+    #[test]
+    fn hard_cuts_a_single_word_longer_than_max_len() {
+        let chunks = split_into_chunks("Supercalifragilisticexpialidocious", 5);
+        assert!(chunks.iter().all(|chunk| chunk.chars().count() <= 5));
+        assert_eq!(chunks.concat(), "Supercalifragilisticexpialidocious");
+    }
+
+    #[test]
+    fn splits_multi_byte_text_on_char_boundaries() {
+        let text = "héllo wörld, how are yoü? Grüße!";
+        let chunks = split_into_chunks(text, 10);
+
+        // Slicing at a char (not byte) index must not panic on multi-byte
+        // chars, and no chunk should overrun the requested length.
+        assert!(chunks.iter().all(|chunk| chunk.chars().count() <= 10));
+
+        // Only whitespace is ever dropped (by the boundary `trim()` calls);
+        // every other character survives the split.
+        let original_non_ws: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+        let rebuilt_non_ws: String = chunks
+            .iter()
+            .flat_map(|chunk| chunk.chars())
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        assert_eq!(original_non_ws, rebuilt_non_ws);
+    }
+}
+
+/// Runs one synthesis request under the deadline monitor and concurrency
+/// semaphore, without touching the cache.
+async fn fetch_chunk(
     state: &State,
     text: &str,
     voice: &str,
     speaking_rate: f32,
-    preferred_format: Option<&str>,
+    format: AudioFormat,
     max_length: Option<u64>,
     hit_any_deadline: Arc<AtomicBool>,
 ) -> Result<(bytes::Bytes, Option<HeaderValue>)> {
-    let _guard = DeadlineMonitor::new(Duration::from_millis(4_000), hit_any_deadline, |took| {
+    let call_start = tokio::time::Instant::now();
+    let _guard = DeadlineMonitor::new(FETCH_DEADLINE, hit_any_deadline, |took| {
         tracing::warn!("Fetching Gwent audio took {} millis!", took.as_millis());
     });
 
+    let queue_start = tokio::time::Instant::now();
     let _permit = state.semaphore.acquire().await?;
-    let format = AudioFormat::parse(preferred_format);
+    let queue_wait_ms = queue_start.elapsed().as_millis() as u64;
 
     let payload = TtsRequest {
         text,
@@ -230,14 +721,9 @@ pub async fn get_tts(
         max_length,
     };
 
-    let req_url = state.endpoint_url(&state.tts_path);
-    let resp = state.client.post(req_url).json(&payload).send().await?;
-
-    if !resp.status().is_success() {
-        let status = resp.status();
-        let body = resp.text().await.unwrap_or_default();
-        anyhow::bail!("Gwent daemon request failed ({status}): {body}");
-    }
+    let rtt_start = tokio::time::Instant::now();
+    let resp = request_tts(state, &payload).await?;
+    let daemon_rtt_ms = rtt_start.elapsed().as_millis() as u64;
 
     let mut content_type = resp.headers().get(CONTENT_TYPE).cloned();
     let audio = resp.bytes().await?;
@@ -245,5 +731,208 @@ pub async fn get_tts(
         content_type = Some(format.default_content_type());
     }
 
+    state.metrics.record_success();
+    if call_start.elapsed() >= FETCH_DEADLINE {
+        state.metrics.record_deadline_breach();
+    }
+
+    tracing::info!(
+        queue_wait_ms,
+        daemon_rtt_ms,
+        bytes = audio.len(),
+        "Gwent chunk synthesized"
+    );
+
+    Ok((audio, content_type))
+}
+
+pub async fn get_tts(
+    state: &State,
+    text: &str,
+    voice: &str,
+    speaking_rate: f32,
+    preferred_format: Option<&str>,
+    max_length: Option<u64>,
+    hit_any_deadline: Arc<AtomicBool>,
+) -> Result<(bytes::Bytes, Option<HeaderValue>)> {
+    let format = AudioFormat::parse(preferred_format);
+    let span = tracing::info_span!(
+        "gwent_get_tts",
+        voice = %voice,
+        format = format.as_str(),
+        text_len = text.chars().count(),
+        speaking_rate,
+    );
+
+    get_tts_inner(
+        state,
+        text,
+        voice,
+        speaking_rate,
+        format,
+        max_length,
+        hit_any_deadline,
+    )
+    .instrument(span)
+    .await
+}
+
+async fn get_tts_inner(
+    state: &State,
+    text: &str,
+    voice: &str,
+    speaking_rate: f32,
+    format: AudioFormat,
+    max_length: Option<u64>,
+    hit_any_deadline: Arc<AtomicBool>,
+) -> Result<(bytes::Bytes, Option<HeaderValue>)> {
+    let key = cache_key(text, voice, speaking_rate, format, max_length);
+
+    if let Some(hit) = state.cache.get(&key).await {
+        return Ok(hit);
+    }
+
+    let chunk_len = max_length
+        .and_then(|n| usize::try_from(n).ok())
+        .unwrap_or(state.chunk_threshold);
+    let chunks = if text.chars().count() > chunk_len {
+        split_into_chunks(text, chunk_len)
+    } else {
+        vec![text.to_owned()]
+    };
+
+    if chunks.len() > 1 && !format.supports_gapless_concatenation() {
+        tracing::warn!(
+            "Gwent audio format {} does not support gapless concatenation; {} chunks will be \
+             stitched back-to-back rather than as one seamless clip",
+            format.as_str(),
+            chunks.len()
+        );
+    }
+
+    let results = futures::future::try_join_all(chunks.iter().map(|chunk| {
+        fetch_chunk(
+            state,
+            chunk,
+            voice,
+            speaking_rate,
+            format,
+            max_length,
+            hit_any_deadline.clone(),
+        )
+    }))
+    .await?;
+
+    let content_type = results
+        .first()
+        .and_then(|(_, content_type)| content_type.clone());
+
+    let mut combined = bytes::BytesMut::new();
+    for (chunk_audio, _) in &results {
+        combined.extend_from_slice(chunk_audio);
+    }
+    let audio = combined.freeze();
+
+    state
+        .cache
+        .put(key, audio.clone(), content_type.clone())
+        .await;
+
     Ok((audio, content_type))
 }
+
+/// A chunked audio response from the Gwent daemon, yielded as it arrives.
+///
+/// Holds the `DeadlineMonitor` guard and the concurrency permit for its
+/// entire lifetime, so the request still counts against
+/// `GWENT_MAX_CONCURRENCY` (and can still trip the deadline warning) until
+/// the caller has finished draining the stream.
+pub struct AudioStream {
+    inner: Pin<Box<dyn Stream<Item = reqwest::Result<bytes::Bytes>> + Send>>,
+    content_type: Option<HeaderValue>,
+    _permit: tokio::sync::OwnedSemaphorePermit,
+    _deadline_guard: DeadlineMonitor,
+}
+
+impl AudioStream {
+    /// Resolved `Content-Type` of the response: the daemon's header, or the
+    /// requested format's default content type when the daemon sent none.
+    pub fn content_type(&self) -> Option<&HeaderValue> {
+        self.content_type.as_ref()
+    }
+}
+
+impl Stream for AudioStream {
+    type Item = reqwest::Result<bytes::Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+/// Streams one synthesis request from a single endpoint. Unlike `get_tts`,
+/// this has no way to concatenate multiple daemon responses into one
+/// ongoing stream, so it does not chunk long text; instead it refuses
+/// text that would overrun the chunking threshold rather than silently
+/// letting the daemon truncate it. Callers with long text should use
+/// `get_tts`, which chunks and concatenates automatically.
+pub async fn get_tts_stream(
+    state: &State,
+    text: &str,
+    voice: &str,
+    speaking_rate: f32,
+    preferred_format: Option<&str>,
+    max_length: Option<u64>,
+    hit_any_deadline: Arc<AtomicBool>,
+) -> Result<AudioStream> {
+    let format = AudioFormat::parse(preferred_format);
+
+    let text_len = text.chars().count();
+    let chunk_len = max_length
+        .and_then(|n| usize::try_from(n).ok())
+        .unwrap_or(state.chunk_threshold);
+    if text_len > chunk_len {
+        anyhow::bail!(
+            "Text is {text_len} chars, which exceeds the {chunk_len} char limit for a single \
+             streamed Gwent request; use get_tts instead, which chunks and concatenates long text"
+        );
+    }
+
+    let call_start = tokio::time::Instant::now();
+    let deadline_guard = DeadlineMonitor::new(FETCH_DEADLINE, hit_any_deadline, |took| {
+        tracing::warn!("Fetching Gwent audio took {} millis!", took.as_millis());
+    });
+
+    let permit = state.semaphore.clone().acquire_owned().await?;
+
+    let payload = TtsRequest {
+        text,
+        voice,
+        speaking_rate,
+        format: format.as_str(),
+        max_length,
+    };
+
+    let resp = request_tts(state, &payload).await?;
+
+    // Counts the response headers arriving as a success; unlike `get_tts`,
+    // this doesn't wait for the body, so it can't detect a stream that
+    // errors partway through.
+    state.metrics.record_success();
+    if call_start.elapsed() >= FETCH_DEADLINE {
+        state.metrics.record_deadline_breach();
+    }
+
+    let content_type = resp
+        .headers()
+        .get(CONTENT_TYPE)
+        .cloned()
+        .or_else(|| Some(format.default_content_type()));
+
+    Ok(AudioStream {
+        inner: Box::pin(resp.bytes_stream()),
+        content_type,
+        _permit: permit,
+        _deadline_guard: deadline_guard,
+    })
+}